@@ -1,156 +1,337 @@
-use std::{error::Error, fs::File, io::Write, slice::Iter};
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt,
+    fs::File,
+    io::{self, ErrorKind, Write},
+    num::ParseIntError,
+};
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 pub fn run(
     content: &str,
     writer: &mut impl Writer,
     max_len: usize,
     align: &Align,
-) -> Result<(), Box<dyn Error>> {
-    let words = content.split(' ').collect::<Vec<&str>>();
-
-    match align {
-        Align::Left => {
-            process(
-                &|line: &Line, writer: &mut dyn Writer, _: usize| {
-                    let mut words_iter = line.iter();
-                    writer.write(words_iter.next().unwrap());
-
-                    words_iter.for_each(|w| writer.write(&format!(" {w}")));
-                },
-                &words,
-                writer,
-                max_len,
-            );
+    overflow: &Overflow,
+) -> Result<(), AlignError> {
+    // Preserve the document structure: paragraphs (separated by blank lines)
+    // and hard line breaks within them are laid out independently, and the
+    // blank-line separators are re-emitted between paragraphs.
+    for (idx, (blanks_before, paragraph)) in paragraphs(content).into_iter().enumerate() {
+        if idx != 0 {
+            for _ in 0..blanks_before {
+                writer.write("\n")?;
+            }
         }
-        Align::Right => {
-            process(
-                &|line: &Line, writer: &mut dyn Writer, len: usize| {
-                    let free_space = len - line.len;
-
-                    for _ in 0..free_space {
-                        writer.write(" ");
-                    }
-
-                    let mut words_iter = line.iter();
-                    writer.write(words_iter.next().unwrap());
-
-                    words_iter.for_each(|w| writer.write(&format!(" {w}")));
-                },
-                &words,
-                writer,
-                max_len,
-            );
+
+        for unit in paragraph {
+            match align {
+                Align::Left => process(left_line, &unit, writer, max_len, *overflow)?,
+                Align::Right => process(right_line, &unit, writer, max_len, *overflow)?,
+                Align::Center => process(center_line, &unit, writer, max_len, *overflow)?,
+                Align::Justify => process(justify_line, &unit, writer, max_len, *overflow)?,
+                Align::Optimal => process_optimal(&unit, writer, max_len, *overflow)?,
+            }
         }
-        Align::Justify => process(
-            &|line: &Line, writer: &mut dyn Writer, len: usize| {
-                let free_space = len - line.len;
-
-                let gaps = if line.words_count == 1 {
-                    1
-                } else {
-                    line.words_count - 1
-                };
-
-                let big_jump = free_space / gaps;
-                let mut small_jump = free_space % gaps;
-
-                let mut words_iter = line.iter();
-                writer.write(words_iter.next().unwrap());
-
-                words_iter.for_each(|w| {
-                    for _ in 0..big_jump {
-                        writer.write(" ");
-                    }
-
-                    if small_jump != 0 {
-                        writer.write(" ");
-                        small_jump -= 1;
-                    }
-
-                    writer.write(&format!(" {w}"))
-                });
-            },
-            &words,
-            writer,
-            max_len,
-        ),
+    }
+
+    Ok(())
+}
+
+/// Split `content` into paragraphs, each a list of its hard-break lines, each a
+/// list of words. A paragraph is a maximal run of non-blank lines; the number
+/// of blank lines that separated it from the previous paragraph is carried
+/// alongside so the exact separator width round-trips.
+fn paragraphs(content: &str) -> Vec<(usize, ParagraphLines<'_>)> {
+    let mut paragraphs = Vec::new();
+    let mut current: ParagraphLines = Vec::new();
+    let mut blanks = 0;
+    let mut seen_paragraph = false;
+
+    for line in content.split('\n') {
+        let words: Vec<&str> = line.split(' ').filter(|w| !w.is_empty()).collect();
+
+        if words.is_empty() {
+            if !current.is_empty() {
+                paragraphs.push((blanks, std::mem::take(&mut current)));
+                seen_paragraph = true;
+                blanks = 0;
+            }
+            // trailing blanks after the last paragraph are dropped, not emitted
+            if seen_paragraph {
+                blanks += 1;
+            }
+        } else {
+            current.push(words);
+        }
+    }
+
+    if !current.is_empty() {
+        paragraphs.push((blanks, current));
+    }
+
+    paragraphs
+}
+
+type ParagraphLines<'a> = Vec<Vec<&'a str>>;
+
+/// Left-align a wrapped line: the words joined by single spaces, no padding.
+fn left_line(line: &Line, writer: &mut dyn Writer, _len: usize) -> Result<(), AlignError> {
+    let mut words_iter = line.iter();
+    writer.write(words_iter.next().unwrap())?;
+
+    for w in words_iter {
+        writer.write(&format!(" {w}"))?;
+    }
+
+    Ok(())
+}
+
+/// Right-align a wrapped line: the free space is emitted as leading padding.
+fn right_line(line: &Line, writer: &mut dyn Writer, len: usize) -> Result<(), AlignError> {
+    let free_space = len.saturating_sub(line.len);
+
+    for _ in 0..free_space {
+        writer.write(" ")?;
+    }
+
+    let mut words_iter = line.iter();
+    writer.write(words_iter.next().unwrap())?;
+
+    for w in words_iter {
+        writer.write(&format!(" {w}"))?;
+    }
+
+    Ok(())
+}
+
+/// Center a wrapped line, biasing the odd column to the right pad so the text
+/// leans left.
+fn center_line(line: &Line, writer: &mut dyn Writer, len: usize) -> Result<(), AlignError> {
+    let free_space = len.saturating_sub(line.len);
+
+    let left_pad = free_space / 2;
+    let right_pad = free_space - left_pad;
+
+    for _ in 0..left_pad {
+        writer.write(" ")?;
+    }
+
+    let mut words_iter = line.iter();
+    writer.write(words_iter.next().unwrap())?;
+
+    for w in words_iter {
+        writer.write(&format!(" {w}"))?;
+    }
+
+    for _ in 0..right_pad {
+        writer.write(" ")?;
+    }
+
+    Ok(())
+}
+
+/// Stretch a single wrapped line across `len` columns by distributing the free
+/// space as evenly as possible between the word gaps.
+fn justify_line(line: &Line, writer: &mut dyn Writer, len: usize) -> Result<(), AlignError> {
+    let free_space = len.saturating_sub(line.len);
+
+    let gaps = if line.words_count == 1 {
+        1
+    } else {
+        line.words_count - 1
+    };
+
+    let big_jump = free_space / gaps;
+    let mut small_jump = free_space % gaps;
+
+    let mut words_iter = line.iter();
+    writer.write(words_iter.next().unwrap())?;
+
+    for w in words_iter {
+        for _ in 0..big_jump {
+            writer.write(" ")?;
+        }
+
+        if small_jump != 0 {
+            writer.write(" ")?;
+            small_jump -= 1;
+        }
+
+        writer.write(&format!(" {w}"))?;
+    }
+
+    Ok(())
+}
+
+/// Break the word stream into lines by dynamic programming, minimising the sum
+/// of squared slack (unused columns) over every line but the last, then emit
+/// each line through [`justify_line`]. The last line is left-aligned rather
+/// than stretched. Unlike [`process`], which greedily fills each line, this
+/// trades a locally loose line for a globally tighter paragraph.
+fn process_optimal(
+    words: &[&str],
+    writer: &mut impl Writer,
+    max_len: usize,
+    overflow: Overflow,
+) -> Result<(), AlignError> {
+    let n = words.len();
+    if n == 0 {
+        return Ok(());
+    }
+
+    // prefix[k] = summed display width of the first k words, so the width of
+    // words i..=j laid out with single spaces is prefix[j + 1] - prefix[i] + (j - i)
+    let mut prefix = vec![0usize; n + 1];
+    for k in 0..n {
+        prefix[k + 1] = prefix[k] + words[k].width();
+    }
+    let seg_width = |i: usize, j: usize| prefix[j + 1] - prefix[i] + (j - i);
+
+    const INF: u64 = u64::MAX;
+    let mut best = vec![INF; n + 1];
+    let mut break_at = vec![0usize; n + 1];
+    best[0] = 0;
+
+    for k in 1..=n {
+        // lay out words j..=k-1 as one line; widening as j shrinks
+        for j in (0..k).rev() {
+            let single = j == k - 1;
+            let width = seg_width(j, k - 1);
+
+            if width > max_len && !single {
+                // any smaller j only makes the line wider, so stop scanning
+                break;
+            }
+            if best[j] == INF {
+                continue;
+            }
+
+            // the last line is never stretched, so it contributes no slack cost
+            let cost = if k == n {
+                0
+            } else {
+                let slack = max_len.saturating_sub(width) as u64;
+                slack * slack
+            };
+
+            let total = best[j].saturating_add(cost);
+            if total < best[k] {
+                best[k] = total;
+                break_at[k] = j;
+            }
+        }
+    }
+
+    // reconstruct the chosen breakpoints as (start, end) inclusive ranges
+    let mut lines = Vec::new();
+    let mut k = n;
+    while k > 0 {
+        let j = break_at[k];
+        lines.push((j, k - 1));
+        k = j;
+    }
+    lines.reverse();
+
+    // Re-emit each chosen line through `process` so the overflow policy still
+    // splits an over-long word (the DP may place one on its own line): the last
+    // line is left-aligned, the rest justified.
+    let last = lines.len() - 1;
+    for (idx, (i, j)) in lines.iter().enumerate() {
+        let spacing = if idx == last { left_line } else { justify_line };
+        process(spacing, &words[*i..=*j], writer, max_len, overflow)?;
     }
 
     Ok(())
 }
 
 fn process(
-    on_line_wrap: &impl Fn(&Line, &mut dyn Writer, usize) -> (),
-    words: &Vec<&str>,
+    on_line_wrap: fn(&Line, &mut dyn Writer, usize) -> Result<(), AlignError>,
+    words: &[&str],
     writer: &mut impl Writer,
     max_len: usize,
-) {
-    let mut line = Line::new(max_len);
-    let mut words_iter = words.iter();
-
-    'line: loop {
-        for word in &mut words_iter {
-            match line.push(word) {
-                LineState::NextWord => (),
+    overflow: Overflow,
+) -> Result<(), AlignError> {
+    let mut line = Line::new(max_len, overflow);
+
+    for &word in words {
+        // a word can outlive one line: a plain wrap retries it on the next
+        // line, an overflow split hands back the remainder to keep placing
+        let mut pending: &str = word;
+
+        loop {
+            match line.push(pending) {
+                LineState::NextWord => break,
                 LineState::Wrap => {
-                    on_line_wrap(&line, writer, max_len);
+                    on_line_wrap(&line, writer, max_len)?;
 
                     line.clear();
-                    line.push(word);
-                    writer.write("\n");
-
-                    continue 'line;
+                    writer.write("\n")?;
                 }
-            };
-        }
+                LineState::Split(remainder) => {
+                    on_line_wrap(&line, writer, max_len)?;
+
+                    line.clear();
+                    writer.write("\n")?;
 
-        if line.words_count > 0 {
-            // remove last character as it's \n
-            let last_word = line.words.last_mut().unwrap();
-            let mut chars = last_word.chars();
-            chars.next_back();
-            line.len -= 1;
-            *last_word = chars.as_str();
-
-            // process the last line
-            on_line_wrap(&line, writer, max_len);
-            writer.write("\n");
+                    pending = remainder;
+                }
+            }
         }
+    }
 
-        break;
+    if line.words_count > 0 {
+        // emit the trailing, not-yet-wrapped line
+        on_line_wrap(&line, writer, max_len)?;
+        writer.write("\n")?;
     }
+
+    Ok(())
 }
 
-enum LineState {
+enum LineState<'a> {
     Wrap,
     NextWord,
+    /// The word was too long for a whole line; its head was placed and the
+    /// remaining tail is handed back to be laid out on the following lines.
+    Split(&'a str),
 }
 
 struct Line<'a> {
     words_count: usize,
     len: usize,
     max_len: usize,
-    words: Vec<&'a str>,
+    overflow: Overflow,
+    words: Vec<Cow<'a, str>>,
 }
 
 impl<'a> Line<'a> {
-    fn new(max_len: usize) -> Self {
+    fn new(max_len: usize, overflow: Overflow) -> Self {
         Self {
             words_count: 0,
             len: 0,
             max_len,
+            overflow,
             words: Vec::new(),
         }
     }
 
-    fn push<'b>(&mut self, word: &'b str) -> LineState
+    fn push<'b>(&mut self, word: &'b str) -> LineState<'b>
     where
         'b: 'a,
     {
-        let word_len = word.len();
+        let word_len = word.width();
 
+        // a word wider than a whole line can never fit, so wrap first if the
+        // current line holds words, then split it at the column boundary
         if word_len > self.max_len {
-            panic!("Got word's length that's longer than the the line length: {word}");
+            if self.words_count != 0 {
+                return LineState::Wrap;
+            }
+
+            return self.split_overflow(word);
         }
 
         let word_len = if self.words_count != 0 {
@@ -160,7 +341,7 @@ impl<'a> Line<'a> {
         };
 
         if word_len + self.len <= self.max_len {
-            self.words.push(word);
+            self.words.push(Cow::Borrowed(word));
             self.len += word_len;
             self.words_count += 1;
 
@@ -170,34 +351,139 @@ impl<'a> Line<'a> {
         }
     }
 
+    /// Place the head of an over-long `word` on this (empty) line and return the
+    /// remainder. With [`Overflow::Hyphenate`] a `-` is appended to the head and
+    /// a column is reserved for it.
+    fn split_overflow<'b>(&mut self, word: &'b str) -> LineState<'b>
+    where
+        'b: 'a,
+    {
+        let hyphenate = matches!(self.overflow, Overflow::Hyphenate) && self.max_len >= 2;
+        let budget = if hyphenate {
+            self.max_len - 1
+        } else {
+            self.max_len
+        };
+
+        let (head, rest, head_width) = split_at_width(word, budget);
+
+        if hyphenate {
+            self.words.push(Cow::Owned(format!("{head}-")));
+            self.len += head_width + 1;
+        } else {
+            self.words.push(Cow::Borrowed(head));
+            self.len += head_width;
+        }
+        self.words_count += 1;
+
+        LineState::Split(rest)
+    }
+
     fn clear(&mut self) {
         self.words_count = 0;
         self.len = 0;
         self.words.clear();
     }
 
-    fn iter(&self) -> Iter<'a, &str> {
-        self.words.iter()
+    fn iter(&self) -> impl Iterator<Item = &str> {
+        self.words.iter().map(|w| w.as_ref())
+    }
+}
+
+/// Split `word` into the longest head whose display width is at most `budget`
+/// and the remaining tail, returning `(head, tail, head_width)`. At least one
+/// character is always consumed so the caller makes progress even when the
+/// first glyph is wider than `budget`.
+fn split_at_width(word: &str, budget: usize) -> (&str, &str, usize) {
+    let mut width = 0;
+    let mut end = 0;
+
+    for (i, c) in word.char_indices() {
+        let c_width = c.width().unwrap_or(0);
+        if end != 0 && width + c_width > budget {
+            break;
+        }
+        width += c_width;
+        end = i + c.len_utf8();
     }
+
+    (&word[..end], &word[end..], width)
 }
 
 #[derive(Debug)]
 pub enum Align {
     Left,
     Right,
+    Center,
     Justify,
+    Optimal,
+}
+
+/// How to handle a word that is wider than a whole line.
+#[derive(Debug, Clone, Copy)]
+pub enum Overflow {
+    /// Hard-split the word at the column boundary, carrying the remainder onto
+    /// the following lines.
+    Break,
+    /// Like [`Overflow::Break`], but insert a `-` at each split when a column
+    /// can be spared for it.
+    Hyphenate,
+}
+
+/// The crate's error type. Carries the failures of argument parsing and of
+/// output: missing or malformed arguments, and I/O errors from a [`Writer`].
+#[derive(Debug)]
+pub enum AlignError {
+    MissingArgument(&'static str),
+    InvalidLength(ParseIntError),
+    UnknownAlign(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for AlignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlignError::MissingArgument(what) => write!(f, "Didn't get {what}"),
+            AlignError::InvalidLength(err) => write!(f, "Line length isn't a valid number: {err}"),
+            AlignError::UnknownAlign(got) => write!(
+                f,
+                "Align option is incorrect. Expected `left`, `right`, `center`, `justify`, `optimal`, got: {got}"
+            ),
+            AlignError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for AlignError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AlignError::InvalidLength(err) => Some(err),
+            AlignError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AlignError {
+    fn from(err: io::Error) -> Self {
+        AlignError::Io(err)
+    }
 }
 
 pub trait Writer {
-    fn write(&mut self, content: &str);
+    fn write(&mut self, content: &str) -> Result<(), AlignError>;
 }
 
 #[derive(Debug)]
 pub struct StdoutWriter;
 
 impl Writer for StdoutWriter {
-    fn write(&mut self, content: &str) {
-        print!("{content}");
+    fn write(&mut self, content: &str) -> Result<(), AlignError> {
+        match io::stdout().write_all(content.as_bytes()) {
+            // a downstream reader closing the pipe (e.g. `head`) is a clean stop
+            Err(err) if err.kind() == ErrorKind::BrokenPipe => Ok(()),
+            other => other.map_err(AlignError::Io),
+        }
     }
 }
 
@@ -207,22 +493,21 @@ pub struct FileWriter {
 }
 
 impl Writer for FileWriter {
-    fn write(&mut self, content: &str) {
-        self.file
-            .write(content.as_bytes())
-            .expect("Wasn't able to write to the file");
+    fn write(&mut self, content: &str) -> Result<(), AlignError> {
+        self.file.write_all(content.as_bytes()).map_err(AlignError::Io)
     }
 }
 
 pub struct Config<'a> {
     pub align: Align,
     pub len: usize,
+    pub overflow: Overflow,
     pub file_path: &'a str,
     pub destination_path: Option<&'a str>,
 }
 
 impl<'a> Config<'a> {
-    pub fn build(args: &'a [String]) -> Result<Self, String> {
+    pub fn build(args: &'a [String]) -> Result<Self, AlignError> {
         let mut args = args.iter();
 
         // skipping the first argument as it's the program name
@@ -230,38 +515,49 @@ impl<'a> Config<'a> {
 
         let file_path = match args.next() {
             Some(f) => f,
-            None => return Err("Didn't get a file path".to_string()),
+            None => return Err(AlignError::MissingArgument("a file path")),
         };
 
         let len = match args.next() {
-            Some(l) => l.parse().expect("expected usize"),
-            None => return Err("Didn't get a string length".to_string()),
+            Some(l) => l.parse().map_err(AlignError::InvalidLength)?,
+            None => return Err(AlignError::MissingArgument("a string length")),
         };
 
         let align = match args.next().map(|a| a.to_lowercase()) {
             Some(a) if a == LEFT => Align::Left,
             Some(a) if a == RIGHT => Align::Right,
+            Some(a) if a == CENTER => Align::Center,
             Some(a) if a == JUSTIFY => Align::Justify,
-            Some(v) => {
-                return Err(format!(
-                    "Align option is incorrect. Expected `left`, `right`, `justify`, got: {v}"
-                ))
-            }
-            None => return Err("Didn't get an align option".to_string()),
+            Some(a) if a == OPTIMAL => Align::Optimal,
+            Some(v) => return Err(AlignError::UnknownAlign(v)),
+            None => return Err(AlignError::MissingArgument("an align option")),
+        };
+
+        // the overflow policy is an optional token right after the align mode;
+        // anything else in that slot is taken to be the destination path
+        let (overflow, destination_path) = match args.next() {
+            Some(a) if a.to_lowercase() == BREAK => (Overflow::Break, args.next()),
+            Some(a) if a.to_lowercase() == HYPHENATE => (Overflow::Hyphenate, args.next()),
+            other => (Overflow::Break, other),
         };
 
         Ok(Self {
             len,
             align,
+            overflow,
             file_path,
-            destination_path: args.next().map(|s| s as &str),
+            destination_path: destination_path.map(|s| s as &str),
         })
     }
 }
 
-const LEFT: &'static str = "left";
-const RIGHT: &'static str = "right";
-const JUSTIFY: &'static str = "justify";
+const LEFT: &str = "left";
+const RIGHT: &str = "right";
+const CENTER: &str = "center";
+const JUSTIFY: &str = "justify";
+const OPTIMAL: &str = "optimal";
+const BREAK: &str = "break";
+const HYPHENATE: &str = "hyphenate";
 
 #[cfg(test)]
 mod tests {
@@ -272,8 +568,9 @@ mod tests {
     }
 
     impl Writer for StringWriter {
-        fn write(&mut self, content: &str) {
+        fn write(&mut self, content: &str) -> Result<(), AlignError> {
             self.val.push_str(content);
+            Ok(())
         }
     }
 
@@ -282,17 +579,39 @@ mod tests {
         let content = String::from("Hi there! My name is Roben Li.\n");
         let mut writer = StringWriter { val: String::new() };
 
-        crate::run(&content, &mut writer, 10, &Align::Justify).unwrap();
+        crate::run(&content, &mut writer, 10, &Align::Justify, &Overflow::Break).unwrap();
 
         assert_eq!("Hi  there!\nMy name is\nRoben  Li.\n", writer.val);
     }
 
+    #[test]
+    fn justifies_content_optimally() {
+        let content = String::from("Hi there! My name is Roben Li.\n");
+        let mut writer = StringWriter { val: String::new() };
+
+        crate::run(&content, &mut writer, 10, &Align::Optimal, &Overflow::Break).unwrap();
+
+        // the last line is left-aligned, not stretched like the greedy justifier
+        assert_eq!("Hi  there!\nMy name is\nRoben Li.\n", writer.val);
+    }
+
+    #[test]
+    fn optimal_breaks_over_long_word() {
+        let content = String::from("abcdefghij foo bar");
+        let mut writer = StringWriter { val: String::new() };
+
+        // a word wider than the line must be split, not trigger an overflow panic
+        crate::run(&content, &mut writer, 5, &Align::Optimal, &Overflow::Break).unwrap();
+
+        assert_eq!("abcde\nfghij\nfoo\nbar\n", writer.val);
+    }
+
     #[test]
     fn aligns_left() {
         let content = String::from("Hello there! This text should be left-aligned.\n");
         let mut writer = StringWriter { val: String::new() };
 
-        crate::run(&content, &mut writer, 15, &Align::Left).unwrap();
+        crate::run(&content, &mut writer, 15, &Align::Left, &Overflow::Break).unwrap();
 
         assert_eq!(
             "Hello there!\nThis text\nshould be\nleft-aligned.\n",
@@ -305,7 +624,7 @@ mod tests {
         let content = String::from("Gracias! And this text must be right-aligned.\n");
         let mut writer = StringWriter { val: String::new() };
 
-        crate::run(&content, &mut writer, 15, &Align::Right).unwrap();
+        crate::run(&content, &mut writer, 15, &Align::Right, &Overflow::Break).unwrap();
 
         assert_eq!(
             "   Gracias! And\n this text must\n             be\n right-aligned.\n",
@@ -314,14 +633,86 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn fails_to_align_when_word_is_long() {
+    fn aligns_center() {
         let content = String::from("Gracias! And this text must be right-aligned.\n");
-        let mut writer = StringWriter {
-            val: String::from(""),
-        };
+        let mut writer = StringWriter { val: String::new() };
+
+        crate::run(&content, &mut writer, 15, &Align::Center, &Overflow::Break).unwrap();
 
-        // "right-aligned." is 14 symbols where the line width is 10, `run` function should panic
-        crate::run(&content, &mut writer, 10, &Align::Right).unwrap();
+        assert_eq!(
+            " Gracias! And  \nthis text must \n      be       \nright-aligned. \n",
+            writer.val
+        );
+    }
+
+    #[test]
+    fn wraps_on_display_width_for_wide_glyphs() {
+        let content = String::from("你好 世界 test\n");
+        let mut writer = StringWriter { val: String::new() };
+
+        crate::run(&content, &mut writer, 5, &Align::Left, &Overflow::Break).unwrap();
+
+        assert_eq!("你好\n世界\ntest\n", writer.val);
+    }
+
+    #[test]
+    fn measures_combining_marks_as_zero_width() {
+        let content = String::from("cafe\u{0301}\n");
+        let mut writer = StringWriter { val: String::new() };
+
+        crate::run(&content, &mut writer, 10, &Align::Right, &Overflow::Break).unwrap();
+
+        assert_eq!("      cafe\u{0301}\n", writer.val);
+    }
+
+    #[test]
+    fn preserves_paragraphs_and_hard_breaks() {
+        let content = String::from("Hello world\nforced break\n\nSecond paragraph here\n");
+        let mut writer = StringWriter { val: String::new() };
+
+        crate::run(&content, &mut writer, 40, &Align::Left, &Overflow::Break).unwrap();
+
+        assert_eq!(
+            "Hello world\nforced break\n\nSecond paragraph here\n",
+            writer.val
+        );
+    }
+
+    #[test]
+    fn preserves_multiple_blank_lines_between_paragraphs() {
+        let content = String::from("a\n\n\nb\n");
+        let mut writer = StringWriter { val: String::new() };
+
+        crate::run(&content, &mut writer, 40, &Align::Left, &Overflow::Break).unwrap();
+
+        // two blank lines separated the paragraphs, and both are preserved
+        assert_eq!("a\n\n\nb\n", writer.val);
+    }
+
+    #[test]
+    fn breaks_long_word_across_lines() {
+        let content = String::from("Gracias! And this text must be right-aligned.\n");
+        let mut writer = StringWriter { val: String::new() };
+
+        // "right-aligned." is wider than the 10-column line, so it is hard-split
+        crate::run(&content, &mut writer, 10, &Align::Left, &Overflow::Break).unwrap();
+
+        assert_eq!(
+            "Gracias!\nAnd this\ntext must\nbe\nright-alig\nned.\n",
+            writer.val
+        );
+    }
+
+    #[test]
+    fn hyphenates_long_word_across_lines() {
+        let content = String::from("Gracias! And this text must be right-aligned.\n");
+        let mut writer = StringWriter { val: String::new() };
+
+        crate::run(&content, &mut writer, 10, &Align::Left, &Overflow::Hyphenate).unwrap();
+
+        assert_eq!(
+            "Gracias!\nAnd this\ntext must\nbe\nright-ali-\ngned.\n",
+            writer.val
+        );
     }
 }
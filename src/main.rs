@@ -2,9 +2,11 @@ use std::{
     env,
     error::Error,
     fs::{self, File},
+    io::{self, Read},
     process,
 };
 
+use flate2::read::MultiGzDecoder;
 use text_aligner::{run, Config, FileWriter, StdoutWriter};
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -15,31 +17,55 @@ fn main() -> Result<(), Box<dyn Error>> {
         len,
         file_path,
         align,
+        overflow,
     } = Config::build(&args).unwrap_or_else(|err| {
         eprintln!("Problem parsing parameters: {err}");
         process::exit(1);
     });
 
+    let content = read_content(file_path)?;
+
+    // a `-` destination (or none at all) streams to stdout, anything else is a file
     match destination_path {
-        Some(path) => {
+        Some(path) if path != STDIO => {
             run(
-                &fs::read_to_string(file_path)?,
+                &content,
                 &mut FileWriter {
                     file: File::create(path)?,
                 },
                 len,
                 &align,
+                &overflow,
             )?;
         }
-        None => {
-            run(
-                &fs::read_to_string(file_path)?,
-                &mut StdoutWriter,
-                len,
-                &align,
-            )?;
+        _ => {
+            run(&content, &mut StdoutWriter, len, &align, &overflow)?;
         }
     }
 
     Ok(())
 }
+
+/// Read the whole input into a `String`, reading from stdin when `path` is `-`
+/// and transparently decoding gzip when the path ends in `.gz` or the gzip
+/// magic bytes are present.
+fn read_content(path: &str) -> Result<String, Box<dyn Error>> {
+    let raw = if path == STDIO {
+        let mut buf = Vec::new();
+        io::stdin().read_to_end(&mut buf)?;
+        buf
+    } else {
+        fs::read(path)?
+    };
+
+    if path.ends_with(".gz") || raw.starts_with(&GZIP_MAGIC) {
+        let mut content = String::new();
+        MultiGzDecoder::new(&raw[..]).read_to_string(&mut content)?;
+        Ok(content)
+    } else {
+        Ok(String::from_utf8(raw)?)
+    }
+}
+
+const STDIO: &str = "-";
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];